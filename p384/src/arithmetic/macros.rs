@@ -21,9 +21,20 @@
 /// - `pub fn is_zero`
 /// - `pub fn double`
 /// - `pub fn invert`
+/// - `pub fn batch_invert`
+/// - `pub fn pow_vartime`
+/// - `pub fn sqrt`
+/// - `pub fn from_okm` (hash-to-field per RFC 9380, with `$l` the per-field expansion length)
 ///
-/// NOTE: field implementations must provide their own inherent `pub fn sqrt`
-/// method in order for the code generated by this macro to compile.
+/// `sqrt` is generated from one of two algorithms, selected with a trailing
+/// `sqrt = ...` argument:
+///
+/// - `sqrt = p3mod4($exp)`: for fields where `p ≡ 3 (mod 4)`, using the identity
+///   `sqrt(self) = self^((p+1)/4)` with `$exp` the precomputed exponent `(p+1)/4`.
+/// - `sqrt = tonelli_shanks($t, $t_plus_1_div_2)`: the constant-time Tonelli–Shanks
+///   variant for general moduli, where `$t` is the odd part `(p-1)/2^S` and
+///   `$t_plus_1_div_2` is the precomputed exponent `(t+1)/2`. Uses `S` and
+///   `ROOT_OF_UNITY` from the `PrimeField` impl below.
 ///
 /// # Trait impls
 /// - `AsRef<$arr>`
@@ -36,6 +47,11 @@
 /// - `Eq`
 /// - `Field`
 /// - `PartialEq`
+/// - `PrimeField`
+/// - `PrimeFieldBits` (behind the `bits` feature)
+/// - `core::iter::Sum`
+/// - `core::iter::Product`
+/// - `serde::Serialize` / `serde::Deserialize` (behind the `serde` feature)
 ///
 /// ## Ops
 /// - `Add`
@@ -62,7 +78,142 @@ macro_rules! impl_sec1_field_element {
         $divstep:ident,
         $msat:ident,
         $mod:expr,
-        $one:expr
+        $modulus_str:expr,
+        $one:expr,
+        $two_inv:expr,
+        $multiplicative_generator:expr,
+        $s:expr,
+        $root_of_unity:expr,
+        $root_of_unity_inv:expr,
+        $delta:expr,
+        $l:expr,
+        $expand_shift:expr,
+        sqrt = p3mod4($sqrt_exp:expr)
+    ) => {
+        impl_sec1_field_element!(
+            @base
+            $fe, $uint, $bytes, $arr, $from_mont, $to_mont, $add, $sub, $mul, $neg, $square,
+            $divstep_precomp, $divstep, $msat, $mod, $modulus_str, $one, $two_inv,
+            $multiplicative_generator, $s, $root_of_unity, $root_of_unity_inv, $delta,
+            $l, $expand_shift
+        );
+
+        impl $fe {
+            /// Returns the square root of this element, if it exists, using the
+            /// `p ≡ 3 (mod 4)` identity `sqrt(a) = a^((p+1)/4)`.
+            pub fn sqrt(&self) -> ::elliptic_curve::subtle::CtOption<Self> {
+                use ::elliptic_curve::subtle::ConstantTimeEq;
+
+                let candidate = self.pow_vartime(&$sqrt_exp);
+                ::elliptic_curve::subtle::CtOption::new(candidate, candidate.square().ct_eq(self))
+            }
+        }
+    };
+    (
+        $fe:tt,
+        $uint:ty,
+        $bytes:ty,
+        $arr:ty,
+        $from_mont:ident,
+        $to_mont:ident,
+        $add:ident,
+        $sub:ident,
+        $mul:ident,
+        $neg:ident,
+        $square:ident,
+        $divstep_precomp:ident,
+        $divstep:ident,
+        $msat:ident,
+        $mod:expr,
+        $modulus_str:expr,
+        $one:expr,
+        $two_inv:expr,
+        $multiplicative_generator:expr,
+        $s:expr,
+        $root_of_unity:expr,
+        $root_of_unity_inv:expr,
+        $delta:expr,
+        $l:expr,
+        $expand_shift:expr,
+        sqrt = tonelli_shanks($sqrt_t:expr, $sqrt_t_plus_1_div_2:expr)
+    ) => {
+        impl_sec1_field_element!(
+            @base
+            $fe, $uint, $bytes, $arr, $from_mont, $to_mont, $add, $sub, $mul, $neg, $square,
+            $divstep_precomp, $divstep, $msat, $mod, $modulus_str, $one, $two_inv,
+            $multiplicative_generator, $s, $root_of_unity, $root_of_unity_inv, $delta,
+            $l, $expand_shift
+        );
+
+        impl $fe {
+            /// Returns the square root of this element, if it exists, using the
+            /// constant-time Tonelli–Shanks algorithm.
+            ///
+            /// See [Sean Bowe's note on the algorithm](https://eprint.iacr.org/2012/685.pdf)
+            /// (page 12, algorithm 5) for the derivation of the fixed-iteration-count loop
+            /// below.
+            pub fn sqrt(&self) -> ::elliptic_curve::subtle::CtOption<Self> {
+                use ::elliptic_curve::ff::PrimeField;
+                use ::elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq};
+
+                let mut x = self.pow_vartime(&$sqrt_t_plus_1_div_2);
+                let mut b = self.pow_vartime(&$sqrt_t);
+                let mut z = Self::ROOT_OF_UNITY;
+                let mut v = Self::S;
+
+                for max_v in (1..=Self::S).rev() {
+                    let mut k = 1u32;
+                    let mut tmp = b.square();
+                    let mut j_less_than_v = Choice::from(1u8);
+
+                    for j in 2..max_v {
+                        let tmp_is_one = tmp.ct_eq(&Self::ONE);
+                        let squared = Self::conditional_select(&tmp, &z, tmp_is_one).square();
+                        tmp = Self::conditional_select(&squared, &tmp, tmp_is_one);
+                        let new_z = Self::conditional_select(&z, &z.square(), tmp_is_one);
+                        j_less_than_v &= !j.ct_eq(&v);
+                        k = u32::conditional_select(&j, &k, tmp_is_one);
+                        z = Self::conditional_select(&z, &new_z, j_less_than_v);
+                    }
+
+                    let result = x * z;
+                    x = Self::conditional_select(&result, &x, b.ct_eq(&Self::ONE));
+                    z = z.square();
+                    b *= z;
+                    v = k;
+                }
+
+                ::elliptic_curve::subtle::CtOption::new(x, x.square().ct_eq(self))
+            }
+        }
+    };
+    (
+        @base
+        $fe:tt,
+        $uint:ty,
+        $bytes:ty,
+        $arr:ty,
+        $from_mont:ident,
+        $to_mont:ident,
+        $add:ident,
+        $sub:ident,
+        $mul:ident,
+        $neg:ident,
+        $square:ident,
+        $divstep_precomp:ident,
+        $divstep:ident,
+        $msat:ident,
+        $mod:expr,
+        $modulus_str:expr,
+        $one:expr,
+        $two_inv:expr,
+        $multiplicative_generator:expr,
+        $s:expr,
+        $root_of_unity:expr,
+        $root_of_unity_inv:expr,
+        $delta:expr,
+        $l:expr,
+        $expand_shift:expr
     ) => {
         impl $fe {
             /// Zero element.
@@ -263,6 +414,97 @@ macro_rules! impl_sec1_field_element {
                 $square(ret.as_mut(), self.as_ref());
                 Self(ret)
             }
+
+            /// Exponentiates `self` by `exp`, where `exp` is a little-endian array of
+            /// [`u64`] limbs.
+            ///
+            /// Note that this method leaks the bit pattern of `exp` through timing. This
+            /// is fine when `exp` is public information, such as a fixed exponent used to
+            /// compute a square root.
+            pub fn pow_vartime(&self, exp: &[u64]) -> Self {
+                let mut res = Self::ONE;
+
+                for w in exp.iter().rev() {
+                    for i in (0..64).rev() {
+                        res = res.square();
+
+                        if ((w >> i) & 1) == 1 {
+                            res *= self;
+                        }
+                    }
+                }
+
+                res
+            }
+
+            /// Create a [`
+            #[doc = stringify!($fe)]
+            /// `] from an expanded message as described in [RFC 9380 § 5.3].
+            ///
+            /// `okm` is split into two halves, each of which is narrower than the field's
+            /// bit length and so can be passed straight to [`Self::from_uint_unchecked`]
+            /// without risk of overflowing the modulus. The two halves are then recombined
+            /// with ordinary field arithmetic, which keeps the result reduced mod p.
+            ///
+            /// [RFC 9380 § 5.3]: https://www.rfc-editor.org/rfc/rfc9380.html#name-hash_to_field-implementatio
+            pub fn from_okm(okm: &[u8; $l]) -> Self {
+                const HALF: usize = $l / 2;
+                const SHIFT: $fe = $fe(<$uint>::from_be_hex($expand_shift));
+
+                let mut buf = <$bytes>::default();
+                let pad = buf.as_ref().len() - HALF;
+                buf.as_mut()[pad..].copy_from_slice(&okm[..HALF]);
+                let d0 = Self::from_uint_unchecked(<$uint>::from_be_byte_array(buf));
+
+                let mut buf = <$bytes>::default();
+                let pad = buf.as_ref().len() - ($l - HALF);
+                buf.as_mut()[pad..].copy_from_slice(&okm[HALF..]);
+                let d1 = Self::from_uint_unchecked(<$uint>::from_be_byte_array(buf));
+
+                d0 * SHIFT + d1
+            }
+
+            /// Invert a batch of field elements using Montgomery's trick, so that only a
+            /// single [`Self::invert`] is performed for the whole batch.
+            ///
+            /// Returns a [`::elliptic_curve::subtle::CtOption`] whose `is_some()` is `true` if
+            /// and only if every element of `items` was nonzero. Elements which are zero are
+            /// left unchanged (i.e. zero) in `items`.
+            pub fn batch_invert(
+                items: &mut [Self],
+            ) -> ::elliptic_curve::subtle::CtOption<()> {
+                use ::elliptic_curve::subtle::{Choice, ConditionallySelectable};
+
+                let n = items.len();
+                let mut scratch = ::alloc::vec::Vec::with_capacity(n);
+                let mut acc = Self::ONE;
+                let mut all_nonzero = Choice::from(1u8);
+
+                for i in 0..n {
+                    scratch.push(acc);
+                    let is_zero = items[i].is_zero();
+                    all_nonzero &= !is_zero;
+                    let multiplicand = Self::conditional_select(&items[i], &Self::ONE, is_zero);
+                    acc *= multiplicand;
+                }
+
+                let mut acc_inv = match Option::<Self>::from(acc.invert()) {
+                    Some(acc_inv) => acc_inv,
+                    None => Self::ONE,
+                };
+
+                for i in (0..n).rev() {
+                    let is_zero = items[i].is_zero();
+                    let old_item = items[i];
+                    let new_item = acc_inv * scratch[i];
+                    items[i] = Self::conditional_select(&new_item, &Self::ZERO, is_zero);
+
+                    let multiplicand = Self::conditional_select(&old_item, &Self::ONE, is_zero);
+                    acc_inv *= multiplicand;
+                }
+
+                ::elliptic_curve::subtle::CtOption::new((), all_nonzero)
+            }
         }
 
         impl AsRef<$arr> for $fe {
@@ -355,6 +597,108 @@ macro_rules! impl_sec1_field_element {
             }
         }
 
+        impl ::elliptic_curve::ff::PrimeField for $fe {
+            type Repr = $bytes;
+
+            const MODULUS: &'static str = $modulus_str;
+            const NUM_BITS: u32 = <$uint>::BIT_SIZE;
+            const CAPACITY: u32 = <$uint>::BIT_SIZE - 1;
+            const TWO_INV: Self = Self(<$uint>::from_be_hex($two_inv));
+            const MULTIPLICATIVE_GENERATOR: Self =
+                Self(<$uint>::from_be_hex($multiplicative_generator));
+            const S: u32 = $s;
+            const ROOT_OF_UNITY: Self = Self(<$uint>::from_be_hex($root_of_unity));
+            const ROOT_OF_UNITY_INV: Self = Self(<$uint>::from_be_hex($root_of_unity_inv));
+            const DELTA: Self = Self(<$uint>::from_be_hex($delta));
+
+            fn from_repr(repr: Self::Repr) -> ::elliptic_curve::subtle::CtOption<Self> {
+                Self::from_be_bytes(repr)
+            }
+
+            fn to_repr(&self) -> Self::Repr {
+                self.to_be_bytes()
+            }
+
+            fn is_odd(&self) -> Choice {
+                self.is_odd()
+            }
+        }
+
+        #[cfg(feature = "bits")]
+        impl ::elliptic_curve::ff::PrimeFieldBits for $fe {
+            type ReprBits = $arr;
+
+            fn to_le_bits(&self) -> ::elliptic_curve::ff::FieldBits<Self::ReprBits> {
+                self.to_canonical().to_le_byte_array().into()
+            }
+
+            fn char_le_bits() -> ::elliptic_curve::ff::FieldBits<Self::ReprBits> {
+                $mod.to_le_byte_array().into()
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl ::serde::Serialize for $fe {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: ::serde::Serializer,
+            {
+                use ::core::fmt::Write;
+
+                let bytes = self.to_be_bytes();
+
+                if serializer.is_human_readable() {
+                    let mut hex = ::alloc::string::String::with_capacity(bytes.as_ref().len() * 2);
+
+                    for byte in bytes.as_ref() {
+                        write!(hex, "{:02x}", byte).map_err(::serde::ser::Error::custom)?;
+                    }
+
+                    serializer.serialize_str(&hex)
+                } else {
+                    serializer.serialize_bytes(bytes.as_ref())
+                }
+            }
+        }
+
+        #[cfg(feature = "serde")]
+        impl<'de> ::serde::Deserialize<'de> for $fe {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: ::serde::Deserializer<'de>,
+            {
+                use ::serde::de::Error as _;
+
+                let mut repr = <$bytes>::default();
+
+                if deserializer.is_human_readable() {
+                    let hex = <::alloc::string::String as ::serde::Deserialize>::deserialize(
+                        deserializer,
+                    )?;
+
+                    if hex.len() != repr.as_ref().len() * 2 {
+                        return Err(D::Error::custom("invalid hex-encoded field element length"));
+                    }
+
+                    for (byte, chunk) in repr.as_mut().iter_mut().zip(hex.as_bytes().chunks(2)) {
+                        let s = ::core::str::from_utf8(chunk).map_err(D::Error::custom)?;
+                        *byte = u8::from_str_radix(s, 16).map_err(D::Error::custom)?;
+                    }
+                } else {
+                    let slice = <&[u8]>::deserialize(deserializer)?;
+
+                    if slice.len() != repr.as_ref().len() {
+                        return Err(D::Error::custom("invalid field element length"));
+                    }
+
+                    repr.as_mut().copy_from_slice(slice);
+                }
+
+                Option::from(Self::from_be_bytes(repr))
+                    .ok_or_else(|| D::Error::custom("field element value out of range"))
+            }
+        }
+
         impl_field_op!($fe, $uint, Add, add, $add);
         impl_field_op!($fe, $uint, Sub, sub, $sub);
         impl_field_op!($fe, $uint, Mul, mul, $mul);
@@ -411,6 +755,30 @@ macro_rules! impl_sec1_field_element {
                 Self(ret)
             }
         }
+
+        impl ::core::iter::Sum for $fe {
+            fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, ::core::ops::Add::add)
+            }
+        }
+
+        impl<'a> ::core::iter::Sum<&'a $fe> for $fe {
+            fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ZERO, |a, b| a + b)
+            }
+        }
+
+        impl ::core::iter::Product for $fe {
+            fn product<I: Iterator<Item = Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, ::core::ops::Mul::mul)
+            }
+        }
+
+        impl<'a> ::core::iter::Product<&'a $fe> for $fe {
+            fn product<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+                iter.fold(Self::ONE, |a, b| a * b)
+            }
+        }
     };
 }
 
@@ -451,4 +819,370 @@ macro_rules! impl_field_op {
             }
         }
     };
+}
+
+#[cfg(test)]
+mod tests {
+    //! The real curve backends (fiat-crypto generated limb arithmetic) live
+    //! outside this trimmed `arithmetic` module, so these tests instantiate
+    //! `impl_sec1_field_element!` against two tiny hand-rolled prime fields
+    //! (modulus 11, `p ≡ 3 (mod 4)`, and modulus 17, `p ≡ 1 (mod 4)` with
+    //! `S = 4`) purely to exercise the macro-generated code on both `sqrt`
+    //! branches plus `batch_invert`, `from_okm` and the `serde` impls.
+    use ::core::ops::{AddAssign, MulAssign, Neg, SubAssign};
+    use ::elliptic_curve::bigint::{ArrayEncoding, Encoding, U64};
+    use ::elliptic_curve::subtle::{Choice, ConditionallySelectable, ConstantTimeEq, CtOption};
+
+    type Word = u64;
+    type Arr = [Word; 1];
+    type XLimbs = [Word; 2];
+    type Bytes = <U64 as ArrayEncoding>::ByteArray;
+
+    const fn reduce(x: u128, m: u64) -> u64 {
+        (x % m as u128) as u64
+    }
+
+    fn add_mod(out: &mut Arr, x: &Arr, y: &Arr, m: u64) {
+        out[0] = reduce(x[0] as u128 + y[0] as u128, m);
+    }
+
+    fn sub_mod(out: &mut Arr, x: &Arr, y: &Arr, m: u64) {
+        let x = x[0] % m;
+        let y = y[0] % m;
+        out[0] = (x + m - y) % m;
+    }
+
+    fn mul_mod(out: &mut Arr, x: &Arr, y: &Arr, m: u64) {
+        out[0] = reduce(x[0] as u128 * y[0] as u128, m);
+    }
+
+    fn neg_mod(out: &mut Arr, x: &Arr, m: u64) {
+        out[0] = (m - x[0] % m) % m;
+    }
+
+    fn square_mod(out: &mut Arr, x: &Arr, m: u64) {
+        mul_mod(out, x, x, m);
+    }
+
+    // `R = 1`: "Montgomery form" is just the canonical residue, so conversion
+    // in and out of it is a plain reduction.
+    fn to_from_mont(out: &mut Arr, x: &Arr, m: u64) {
+        out[0] = x[0] % m;
+    }
+
+    fn xlimbs_to_i128(a: &XLimbs) -> i128 {
+        ((a[0] as u128) | ((a[1] as u128) << 64)) as i128
+    }
+
+    fn i128_to_xlimbs(v: i128) -> XLimbs {
+        let u = v as u128;
+        [u as u64, (u >> 64) as u64]
+    }
+
+    fn half_mod(x: u64, m: u64) -> u64 {
+        if x % 2 == 0 {
+            x / 2
+        } else {
+            (x + m) / 2
+        }
+    }
+
+    fn msat_mod(f: &mut XLimbs, m: u64) {
+        *f = [m, 0];
+    }
+
+    fn divstep_precomp_mod(out: &mut Arr) {
+        // No deferred correction is needed: `v`/`r` below are tracked as
+        // actual residues mod `m` at every step rather than scaled integers,
+        // so the precomputed correction factor is just `1`.
+        out[0] = 1;
+    }
+
+    /// A binary-gcd-style divstep that tracks `v`/`r` as residues mod `m`
+    /// directly (rather than fiat-crypto's deferred 2-adic scaling), with an
+    /// explicit fixed point once `g == 0` so that running it for more than
+    /// the convergence bound is harmless.
+    #[allow(clippy::many_single_char_names)]
+    fn divstep_mod(
+        out1: &mut Word,
+        out2: &mut XLimbs,
+        out3: &mut XLimbs,
+        out4: &mut Arr,
+        out5: &mut Arr,
+        d: Word,
+        f: &XLimbs,
+        g: &XLimbs,
+        v: &Arr,
+        r: &Arr,
+        m: u64,
+    ) {
+        let d = d as i64;
+        let f_val = xlimbs_to_i128(f);
+        let g_val = xlimbs_to_i128(g);
+        let v_val = v[0] % m;
+        let r_val = r[0] % m;
+
+        let (new_d, new_f, new_g, new_v, new_r) = if g_val == 0 {
+            (d, f_val, g_val, v_val, r_val)
+        } else if d > 0 && (g_val & 1) != 0 {
+            let new_g = (g_val - f_val) / 2;
+            let new_r = half_mod((r_val + m - v_val) % m, m);
+            (1 - d, g_val, new_g, r_val, new_r)
+        } else {
+            let g0 = g_val & 1;
+            let new_g = (g_val + g0 * f_val) / 2;
+            let new_r = half_mod((r_val + (g0 as u64) * v_val % m) % m, m);
+            (1 + d, f_val, new_g, v_val, new_r)
+        };
+
+        *out1 = new_d as u64;
+        *out2 = i128_to_xlimbs(new_f);
+        *out3 = i128_to_xlimbs(new_g);
+        out4[0] = new_v;
+        out5[0] = new_r;
+    }
+
+    // modulus 11 (`p ≡ 3 (mod 4)`) backend
+    fn add11(out: &mut Arr, x: &Arr, y: &Arr) {
+        add_mod(out, x, y, 11)
+    }
+    fn sub11(out: &mut Arr, x: &Arr, y: &Arr) {
+        sub_mod(out, x, y, 11)
+    }
+    fn mul11(out: &mut Arr, x: &Arr, y: &Arr) {
+        mul_mod(out, x, y, 11)
+    }
+    fn neg11(out: &mut Arr, x: &Arr) {
+        neg_mod(out, x, 11)
+    }
+    fn square11(out: &mut Arr, x: &Arr) {
+        square_mod(out, x, 11)
+    }
+    fn to_mont11(out: &mut Arr, x: &Arr) {
+        to_from_mont(out, x, 11)
+    }
+    fn from_mont11(out: &mut Arr, x: &Arr) {
+        to_from_mont(out, x, 11)
+    }
+    fn msat11(f: &mut XLimbs) {
+        msat_mod(f, 11)
+    }
+    fn divstep_precomp11(out: &mut Arr) {
+        divstep_precomp_mod(out)
+    }
+    fn divstep11(
+        out1: &mut Word,
+        out2: &mut XLimbs,
+        out3: &mut XLimbs,
+        out4: &mut Arr,
+        out5: &mut Arr,
+        d: Word,
+        f: &XLimbs,
+        g: &XLimbs,
+        v: &Arr,
+        r: &Arr,
+    ) {
+        divstep_mod(out1, out2, out3, out4, out5, d, f, g, v, r, 11)
+    }
+
+    // modulus 17 (`p ≡ 1 (mod 4)`, `S = 4`) backend
+    fn add17(out: &mut Arr, x: &Arr, y: &Arr) {
+        add_mod(out, x, y, 17)
+    }
+    fn sub17(out: &mut Arr, x: &Arr, y: &Arr) {
+        sub_mod(out, x, y, 17)
+    }
+    fn mul17(out: &mut Arr, x: &Arr, y: &Arr) {
+        mul_mod(out, x, y, 17)
+    }
+    fn neg17(out: &mut Arr, x: &Arr) {
+        neg_mod(out, x, 17)
+    }
+    fn square17(out: &mut Arr, x: &Arr) {
+        square_mod(out, x, 17)
+    }
+    fn to_mont17(out: &mut Arr, x: &Arr) {
+        to_from_mont(out, x, 17)
+    }
+    fn from_mont17(out: &mut Arr, x: &Arr) {
+        to_from_mont(out, x, 17)
+    }
+    fn msat17(f: &mut XLimbs) {
+        msat_mod(f, 17)
+    }
+    fn divstep_precomp17(out: &mut Arr) {
+        divstep_precomp_mod(out)
+    }
+    fn divstep17(
+        out1: &mut Word,
+        out2: &mut XLimbs,
+        out3: &mut XLimbs,
+        out4: &mut Arr,
+        out5: &mut Arr,
+        d: Word,
+        f: &XLimbs,
+        g: &XLimbs,
+        v: &Arr,
+        r: &Arr,
+    ) {
+        divstep_mod(out1, out2, out3, out4, out5, d, f, g, v, r, 17)
+    }
+
+    struct TestFieldP3Mod4(U64);
+
+    impl_sec1_field_element!(
+        TestFieldP3Mod4,
+        U64,
+        Bytes,
+        Arr,
+        from_mont11,
+        to_mont11,
+        add11,
+        sub11,
+        mul11,
+        neg11,
+        square11,
+        divstep_precomp11,
+        divstep11,
+        msat11,
+        U64::from_be_hex("000000000000000b"),
+        "11",
+        "0000000000000001",
+        "0000000000000006",
+        "0000000000000002",
+        1,
+        "000000000000000a",
+        "000000000000000a",
+        "0000000000000004",
+        2,
+        "0000000000000003",
+        sqrt = p3mod4([3u64])
+    );
+
+    struct TestFieldTonelliShanks(U64);
+
+    impl_sec1_field_element!(
+        TestFieldTonelliShanks,
+        U64,
+        Bytes,
+        Arr,
+        from_mont17,
+        to_mont17,
+        add17,
+        sub17,
+        mul17,
+        neg17,
+        square17,
+        divstep_precomp17,
+        divstep17,
+        msat17,
+        U64::from_be_hex("0000000000000011"),
+        "17",
+        "0000000000000001",
+        "0000000000000009",
+        "0000000000000003",
+        4,
+        "0000000000000003",
+        "0000000000000006",
+        "0000000000000001",
+        2,
+        "0000000000000001",
+        sqrt = tonelli_shanks([1u64], [1u64])
+    );
+
+    fn elem<F>(wrap: impl Fn(U64) -> F, value: u64) -> F {
+        wrap(U64::from(value))
+    }
+
+    #[test]
+    fn sqrt_p3mod4_residue_and_non_residue() {
+        let four = elem(TestFieldP3Mod4, 4);
+        let root = Option::<TestFieldP3Mod4>::from(four.sqrt()).unwrap();
+        assert!(bool::from(root.square().ct_eq(&four)));
+
+        let two = elem(TestFieldP3Mod4, 2);
+        assert!(bool::from(two.sqrt().is_none()));
+    }
+
+    #[test]
+    fn sqrt_tonelli_shanks_residue_and_non_residue() {
+        let two = elem(TestFieldTonelliShanks, 2);
+        let root = Option::<TestFieldTonelliShanks>::from(two.sqrt()).unwrap();
+        assert!(bool::from(root.square().ct_eq(&two)));
+
+        let three = elem(TestFieldTonelliShanks, 3);
+        assert!(bool::from(three.sqrt().is_none()));
+    }
+
+    #[test]
+    fn from_okm_known_answer() {
+        // `$l = 2`, `HALF = 1`: each half is a single byte, recombined as
+        // `d0 * SHIFT + d1` with `SHIFT = 3` (the `$expand_shift` constant
+        // wired into `TestFieldP3Mod4` above).
+        let okm = [200u8, 134u8];
+        let expected = elem(TestFieldP3Mod4, 8);
+        assert!(TestFieldP3Mod4::from_okm(&okm) == expected);
+    }
+
+    #[test]
+    fn batch_invert_matches_individual_invert() {
+        let values = [2u64, 5, 7, 3, 9];
+        let mut items: ::alloc::vec::Vec<_> =
+            values.iter().map(|&v| elem(TestFieldP3Mod4, v)).collect();
+
+        let result = TestFieldP3Mod4::batch_invert(&mut items);
+        assert!(bool::from(result.is_some()));
+
+        for (item, &v) in items.iter().zip(&values) {
+            let expected = Option::<TestFieldP3Mod4>::from(elem(TestFieldP3Mod4, v).invert()).unwrap();
+            assert!(*item == expected);
+        }
+    }
+
+    #[test]
+    fn batch_invert_reports_any_zero() {
+        let values = [2u64, 0, 5, 7, 0, 3];
+        let mut items: ::alloc::vec::Vec<_> =
+            values.iter().map(|&v| elem(TestFieldP3Mod4, v)).collect();
+
+        let result = TestFieldP3Mod4::batch_invert(&mut items);
+        assert!(!bool::from(result.is_some()));
+
+        for (item, &v) in items.iter().zip(&values) {
+            if v == 0 {
+                assert!(*item == TestFieldP3Mod4::ZERO);
+            } else {
+                let expected = Option::<TestFieldP3Mod4>::from(elem(TestFieldP3Mod4, v).invert()).unwrap();
+                assert!(*item == expected);
+            }
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_human_readable() {
+        let value = elem(TestFieldP3Mod4, 7);
+        let json = ::serde_json::to_string(&value).unwrap();
+        let decoded: TestFieldP3Mod4 = ::serde_json::from_str(&json).unwrap();
+        assert!(decoded == value);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip_binary() {
+        let value = elem(TestFieldP3Mod4, 7);
+        let encoded = ::bincode::serialize(&value).unwrap();
+        let decoded: TestFieldP3Mod4 = ::bincode::deserialize(&encoded).unwrap();
+        assert!(decoded == value);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_rejects_encoding_at_or_above_modulus() {
+        // The modulus is `11` (`0x0b`); its own big-endian hex encoding must be
+        // rejected by `Deserialize` rather than silently wrapping.
+        let result: ::core::result::Result<TestFieldP3Mod4, _> =
+            ::serde_json::from_str("\"000000000000000b\"");
+        assert!(result.is_err());
+    }
 }
\ No newline at end of file